@@ -0,0 +1,204 @@
+//! User-configurable file-navigation order, read once at startup from a
+//! small TOML file in the XDG config directory (e.g.
+//! `~/.config/iceq/config.toml`). Missing file or bad TOML both fall back
+//! to the previous hard-coded behaviour: sort by name, ascending.
+
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortBy {
+    Name,
+    Modified,
+    Created,
+    Size,
+}
+
+impl Default for SortBy {
+    fn default() -> Self {
+        SortBy::Name
+    }
+}
+
+impl std::fmt::Display for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            SortBy::Name => "name",
+            SortBy::Modified => "modified",
+            SortBy::Created => "created",
+            SortBy::Size => "size",
+        };
+        f.write_str(label)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct NavigationConfig {
+    pub sort_by: SortBy,
+    pub reverse: bool,
+    /// Compare embedded numbers numerically, so `note2.txt` sorts before
+    /// `note10.txt` instead of after it.
+    pub natural: bool,
+}
+
+impl NavigationConfig {
+    /// Reads `$XDG_CONFIG_HOME/iceq/config.toml` (falling back to
+    /// `~/.config/iceq/config.toml`); any error yields the default config.
+    pub fn load() -> Self {
+        let Some(path) = config_path() else {
+            return Self::default();
+        };
+
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// A short human-readable summary for the status line, e.g.
+    /// `"modified desc"` or `"name"` when everything is default.
+    pub fn describe(&self) -> String {
+        if self.reverse {
+            format!("{} desc", self.sort_by)
+        } else {
+            self.sort_by.to_string()
+        }
+    }
+
+    /// Sorts `files` in place according to this configuration.
+    pub fn sort(&self, files: &mut [PathBuf]) {
+        files.sort_by(|a, b| self.compare(a, b));
+        if self.reverse {
+            files.reverse();
+        }
+    }
+
+    fn compare(&self, a: &Path, b: &Path) -> Ordering {
+        match self.sort_by {
+            SortBy::Name if self.natural => natural_compare(a, b),
+            SortBy::Name => a.cmp(b),
+            SortBy::Modified => {
+                metadata_time(a, |m| m.modified()).cmp(&metadata_time(b, |m| m.modified()))
+            }
+            SortBy::Created => {
+                metadata_time(a, |m| m.created()).cmp(&metadata_time(b, |m| m.created()))
+            }
+            SortBy::Size => metadata_len(a).cmp(&metadata_len(b)),
+        }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))?;
+    Some(config_dir.join("iceq").join("config.toml"))
+}
+
+fn metadata_time(
+    path: &Path,
+    extract: impl Fn(&std::fs::Metadata) -> std::io::Result<std::time::SystemTime>,
+) -> std::time::SystemTime {
+    std::fs::metadata(path)
+        .ok()
+        .and_then(|metadata| extract(&metadata).ok())
+        .unwrap_or(std::time::UNIX_EPOCH)
+}
+
+fn metadata_len(path: &Path) -> u64 {
+    std::fs::metadata(path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0)
+}
+
+/// Compares paths by their file names, treating runs of digits as numbers
+/// so `note2` sorts before `note10`.
+fn natural_compare(a: &Path, b: &Path) -> Ordering {
+    let a = a
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    let b = b
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        match (a_chars.peek(), b_chars.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(ac), Some(bc)) if ac.is_ascii_digit() && bc.is_ascii_digit() => {
+                let a_num = take_number(&mut a_chars);
+                let b_num = take_number(&mut b_chars);
+                match a_num.cmp(&b_num) {
+                    Ordering::Equal => continue,
+                    ordering => return ordering,
+                }
+            }
+            (Some(ac), Some(bc)) => match ac.cmp(bc) {
+                Ordering::Equal => {
+                    a_chars.next();
+                    b_chars.next();
+                }
+                ordering => return ordering,
+            },
+        }
+    }
+}
+
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars>) -> u64 {
+    let mut value: u64 = 0;
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        value = value.saturating_mul(10).saturating_add(digit as u64);
+        chars.next();
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_number_reads_a_full_run_of_digits() {
+        let mut chars = "123abc".chars().peekable();
+        assert_eq!(take_number(&mut chars), 123);
+        assert_eq!(chars.collect::<String>(), "abc");
+    }
+
+    #[test]
+    fn take_number_saturates_instead_of_overflowing() {
+        let mut chars = "99999999999999999999".chars().peekable();
+        assert_eq!(take_number(&mut chars), u64::MAX);
+    }
+
+    #[test]
+    fn natural_compare_orders_embedded_numbers_numerically() {
+        let a = PathBuf::from("note2.txt");
+        let b = PathBuf::from("note10.txt");
+        assert_eq!(natural_compare(&a, &b), Ordering::Less);
+    }
+
+    #[test]
+    fn natural_compare_falls_back_to_lexicographic_for_non_numeric_runs() {
+        let a = PathBuf::from("apple.txt");
+        let b = PathBuf::from("banana.txt");
+        assert_eq!(natural_compare(&a, &b), Ordering::Less);
+    }
+
+    #[test]
+    fn natural_compare_treats_equal_names_as_equal() {
+        let a = PathBuf::from("note.txt");
+        let b = PathBuf::from("note.txt");
+        assert_eq!(natural_compare(&a, &b), Ordering::Equal);
+    }
+}
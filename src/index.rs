@@ -0,0 +1,230 @@
+//! An in-memory index of the `.txt` notes in the working directory, plus a
+//! subsequence-based fuzzy matcher used to rank them against a query.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// One indexed note. `title` is the first non-empty line of the file, which
+/// reads much better in a switcher than a timestamped filename.
+#[derive(Debug, Clone)]
+pub struct FileEntry {
+    pub path: PathBuf,
+    pub modified: SystemTime,
+    pub title: String,
+}
+
+/// A persistent index of notes, refreshed incrementally as files change on
+/// disk rather than rebuilt from scratch on every keystroke.
+#[derive(Debug, Default)]
+pub struct FileIndex {
+    entries: Vec<FileEntry>,
+}
+
+impl FileIndex {
+    pub fn entries(&self) -> &[FileEntry] {
+        &self.entries
+    }
+
+    /// Rebuilds the index from every `.txt` file directly under `dir`.
+    pub fn scan(dir: &Path) -> Self {
+        let mut entries = Vec::new();
+        let Ok(read_dir) = std::fs::read_dir(dir) else {
+            return Self { entries };
+        };
+
+        for entry in read_dir.filter_map(Result::ok) {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("txt") {
+                if let Some(entry) = read_entry(&path) {
+                    entries.push(entry);
+                }
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Inserts, updates, or removes a single file's entry, used to keep the
+    /// index fresh from filesystem-watcher events without a full rescan.
+    pub fn refresh(&mut self, path: &Path) {
+        self.entries.retain(|entry| entry.path != path);
+        if let Some(entry) = read_entry(path) {
+            self.entries.push(entry);
+        }
+    }
+
+    pub fn remove(&mut self, path: &Path) {
+        self.entries.retain(|entry| entry.path != path);
+    }
+}
+
+fn read_entry(path: &Path) -> Option<FileEntry> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    let title = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| {
+            contents
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("untitled")
+                .to_string()
+        });
+
+    Some(FileEntry {
+        path: path.to_path_buf(),
+        modified,
+        title,
+    })
+}
+
+/// The result of a successful fuzzy match: a score to rank by, and the
+/// char indices of `candidate` that matched `query`, for highlighting.
+#[derive(Debug, Clone)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    pub positions: Vec<usize>,
+}
+
+/// Subsequence fuzzy matcher: `query`'s characters must appear in `candidate`
+/// in order, but not necessarily contiguously. Scores reward matches that are
+/// consecutive or start a "word" (after `_`, `-`, `/`, or a case transition)
+/// and penalize the gaps between matched characters.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    const BASE_SCORE: i64 = 10;
+    const CONSECUTIVE_BONUS: i64 = 15;
+    const WORD_BOUNDARY_BONUS: i64 = 20;
+    const GAP_PENALTY: i64 = 2;
+
+    let mut positions = Vec::with_capacity(query_chars.len());
+    let mut score = 0i64;
+    let mut query_index = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (index, ch) in candidate_lower.iter().enumerate() {
+        if query_index >= query_chars.len() {
+            break;
+        }
+        if *ch != query_chars[query_index] {
+            continue;
+        }
+
+        score += BASE_SCORE;
+
+        if let Some(last) = last_match {
+            let gap = index - last - 1;
+            if gap == 0 {
+                score += CONSECUTIVE_BONUS;
+            } else {
+                score -= GAP_PENALTY * gap as i64;
+            }
+        }
+
+        if is_word_boundary(&candidate_chars, index) {
+            score += WORD_BOUNDARY_BONUS;
+        }
+
+        positions.push(index);
+        last_match = Some(index);
+        query_index += 1;
+    }
+
+    if query_index < query_chars.len() {
+        return None;
+    }
+
+    Some(FuzzyMatch { score, positions })
+}
+
+fn is_word_boundary(chars: &[char], index: usize) -> bool {
+    let Some(&current) = chars.get(index) else {
+        return false;
+    };
+    let Some(&previous) = index.checked_sub(1).and_then(|i| chars.get(i)) else {
+        return true;
+    };
+
+    matches!(previous, '_' | '-' | '/') || (previous.is_lowercase() && current.is_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query_matches_everything_with_no_positions() {
+        let result = fuzzy_match("", "anything").unwrap();
+        assert_eq!(result.score, 0);
+        assert!(result.positions.is_empty());
+    }
+
+    #[test]
+    fn matches_non_contiguous_characters_as_a_subsequence() {
+        let result = fuzzy_match("nte", "notes").unwrap();
+        assert_eq!(result.positions, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_candidates_missing_a_query_character() {
+        assert!(fuzzy_match("xyz", "notes").is_none());
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        assert!(fuzzy_match("NOT", "notes").is_some());
+    }
+
+    #[test]
+    fn consecutive_match_outscores_same_length_gappy_match() {
+        let consecutive = fuzzy_match("not", "notes").unwrap();
+        let gappy = fuzzy_match("nts", "notes").unwrap();
+        assert!(consecutive.score > gappy.score);
+    }
+
+    #[test]
+    fn word_boundary_match_outscores_mid_word_match() {
+        // Same single-character query, but "b" starts a word in "a-b" (right
+        // after the separator) and sits mid-word in "ab" - boundary wins.
+        let boundary = fuzzy_match("b", "a-b").unwrap();
+        let mid_word = fuzzy_match("b", "ab").unwrap();
+        assert!(boundary.score > mid_word.score);
+    }
+
+    #[test]
+    fn word_boundary_follows_case_transition() {
+        let chars: Vec<char> = "fooBar".chars().collect();
+        assert!(is_word_boundary(&chars, 3)); // 'B' after lowercase 'o'
+        assert!(!is_word_boundary(&chars, 1)); // 'o' after lowercase 'f'
+    }
+
+    #[test]
+    fn word_boundary_follows_separator() {
+        let chars: Vec<char> = "foo_bar".chars().collect();
+        assert!(is_word_boundary(&chars, 4)); // 'b' after '_'
+    }
+
+    #[test]
+    fn first_character_is_always_a_word_boundary() {
+        let chars: Vec<char> = "foo".chars().collect();
+        assert!(is_word_boundary(&chars, 0));
+    }
+}
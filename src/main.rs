@@ -1,11 +1,14 @@
-use iced::Settings;
+use iced::highlighter;
 use iced::keyboard;
 use iced::time;
-use iced::widget::{column, text, text_editor};
+use iced::widget::{
+    button, column, container, rich_text, scrollable, span, stack, text, text_editor, text_input,
+};
 use iced::window;
 use iced::window::Id;
 use iced::window::Mode;
-use iced::{Element, Fill, Font, Subscription, Task, Theme};
+use iced::Settings;
+use iced::{Color, Element, Fill, Font, Length, Subscription, Task, Theme};
 
 use chrono::Local;
 
@@ -13,6 +16,17 @@ use std::io;
 use std::path::PathBuf;
 use std::time::Duration;
 
+mod config;
+mod index;
+mod pairs;
+mod search;
+mod watcher;
+
+use config::NavigationConfig;
+use index::{FileEntry, FileIndex};
+use pairs::PairConfig;
+use search::{SearchHit, SearchIndex};
+
 pub fn main() -> iced::Result {
     iced::application("Distraction-Free Editor", Editor::update, Editor::view)
         .theme(Editor::theme)
@@ -36,6 +50,18 @@ struct Editor {
     content: text_editor::Content,
     is_loading: bool,
     is_dirty: bool,
+    has_disk_conflict: bool,
+    index: FileIndex,
+    switcher: Option<Switcher>,
+    nav_config: NavigationConfig,
+    search_index: SearchIndex,
+    content_search: Option<ContentSearch>,
+    pending_cursor_line: Option<usize>,
+    pair_config: PairConfig,
+    /// The file and content hash this process last wrote via `save_file`,
+    /// so the filesystem watcher's own echo of an autosave isn't mistaken
+    /// for an external change in `Message::FileChangedOnDisk`.
+    last_self_write: Option<(PathBuf, u64)>,
 }
 
 impl Default for Editor {
@@ -45,22 +71,109 @@ impl Default for Editor {
             content: text_editor::Content::new(),
             is_loading: true,
             is_dirty: false,
+            has_disk_conflict: false,
+            index: FileIndex::default(),
+            switcher: None,
+            nav_config: NavigationConfig::default(),
+            search_index: SearchIndex::default(),
+            content_search: None,
+            pending_cursor_line: None,
+            pair_config: PairConfig::default(),
+            last_self_write: None,
         }
     }
 }
 
+/// State for the Cmd+O quick-switcher overlay: a query and the ranked,
+/// fuzzy-matched notes it produced, with highlight positions for the title.
+#[derive(Debug, Default)]
+struct Switcher {
+    query: String,
+    results: Vec<(FileEntry, Vec<usize>)>,
+    selected: usize,
+}
+
+fn switcher_query_id() -> text_input::Id {
+    text_input::Id::new("switcher-query")
+}
+
+/// Color used to highlight the characters of a switcher entry's title that
+/// matched the fuzzy query.
+const MATCH_HIGHLIGHT: Color = Color::from_rgb(0.95, 0.75, 0.2);
+
+/// Builds the switcher row label, coloring the title's matched characters
+/// (from `FuzzyMatch::positions`) so the user can see why an entry ranked.
+fn highlighted_title<'a>(entry: &'a FileEntry, positions: &[usize]) -> Element<'a, Message> {
+    let matched: std::collections::HashSet<usize> = positions.iter().copied().collect();
+
+    let title_spans = entry.title.chars().enumerate().map(|(index, ch)| {
+        let fragment = span(ch.to_string());
+        if matched.contains(&index) {
+            fragment.color(MATCH_HIGHLIGHT)
+        } else {
+            fragment
+        }
+    });
+
+    let spans = title_spans
+        .chain([span("  —  "), span(entry.path.display().to_string())])
+        .collect::<Vec<_>>();
+
+    rich_text(spans).into()
+}
+
+/// State for the Cmd+F full-text search overlay: a query and the ranked
+/// file hits it produced, each with a one-line snippet.
+#[derive(Debug, Default)]
+struct ContentSearch {
+    query: String,
+    results: Vec<SearchHit>,
+    selected: usize,
+}
+
+fn content_search_query_id() -> text_input::Id {
+    text_input::Id::new("content-search-query")
+}
+
 #[derive(Debug, Clone)]
 enum Message {
     ActionPerformed(text_editor::Action),
     FileCreated(Result<PathBuf, Error>),
     AutoSave,
-    FileSaved(Result<PathBuf, Error>),
+    FileSaved(Result<(PathBuf, u64), Error>),
     WindowOpened(Id),
     WindowClosed,
     OpenPreviousFile,
     OpenNextFile,
     CreateNewFile,
     FileLoaded(Result<(PathBuf, String), Error>),
+    FileChangedOnDisk(PathBuf),
+    DiskChangeClassified(DiskChange),
+    ReloadFromDisk,
+    IndexEntryChanged(PathBuf),
+    IndexEntryRemoved(PathBuf),
+    OpenSwitcher,
+    CloseSwitcher,
+    SwitcherQueryChanged(String),
+    SwitcherSelectionMoved(i32),
+    SwitcherFileChosen(PathBuf),
+    OpenContentSearch,
+    CloseContentSearch,
+    SearchContent(String),
+    ContentSearchSelectionMoved(i32),
+    ContentSearchResultChosen(PathBuf, usize),
+}
+
+/// The outcome of reading the file behind a `Message::FileChangedOnDisk`
+/// event and comparing it against our own last write.
+#[derive(Debug, Clone)]
+enum DiskChange {
+    /// The on-disk content matches what we last wrote ourselves - an echo
+    /// of our own autosave, not an external edit.
+    SelfEcho,
+    /// The on-disk content differs from our last write, so this is a real
+    /// external change.
+    Changed(PathBuf),
 }
 
 impl Editor {
@@ -69,11 +182,30 @@ impl Editor {
         let filename = format!("{}.txt", now.format("%Y-%m-%d_%H-%M-%S"));
         let file_path = PathBuf::from(filename);
 
+        let current_dir = std::env::current_dir().ok();
+        let index = current_dir
+            .as_deref()
+            .map(FileIndex::scan)
+            .unwrap_or_default();
+        let search_index = current_dir
+            .as_deref()
+            .map(SearchIndex::scan)
+            .unwrap_or_default();
+
         let editor = Self {
             file: None,
             content: text_editor::Content::new(),
             is_loading: true,
             is_dirty: false,
+            has_disk_conflict: false,
+            index,
+            switcher: None,
+            nav_config: NavigationConfig::load(),
+            search_index,
+            content_search: None,
+            pending_cursor_line: None,
+            pair_config: PairConfig::default(),
+            last_self_write: None,
         };
 
         let tasks = vec![
@@ -87,7 +219,14 @@ impl Editor {
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::ActionPerformed(action) => {
-                self.is_dirty = self.is_dirty || action.is_edit();
+                if action.is_edit() {
+                    self.is_dirty = true;
+                    // Typing through a disk-changed warning is how the user
+                    // tells us to keep their in-memory version. A plain
+                    // cursor move shouldn't dismiss a warning the user
+                    // hasn't acted on yet.
+                    self.has_disk_conflict = false;
+                }
                 self.content.perform(action);
                 Task::none()
             }
@@ -108,7 +247,8 @@ impl Editor {
             }
             Message::FileSaved(result) => {
                 self.is_loading = false;
-                if let Ok(path) = result {
+                if let Ok((path, hash)) = result {
+                    self.last_self_write = Some((path.clone(), hash));
                     self.file = Some(path);
                     self.is_dirty = false;
                 }
@@ -136,8 +276,10 @@ impl Editor {
                     };
 
                     let current_file = self.file.clone();
-                    let load_task =
-                        Task::perform(find_and_load_file(current_file, true), Message::FileLoaded);
+                    let load_task = Task::perform(
+                        find_and_load_file(current_file, true, self.nav_config),
+                        Message::FileLoaded,
+                    );
 
                     Task::batch([save_task, load_task])
                 }
@@ -155,8 +297,10 @@ impl Editor {
                     };
 
                     let current_file = self.file.clone();
-                    let load_task =
-                        Task::perform(find_and_load_file(current_file, false), Message::FileLoaded);
+                    let load_task = Task::perform(
+                        find_and_load_file(current_file, false, self.nav_config),
+                        Message::FileLoaded,
+                    );
 
                     Task::batch([save_task, load_task])
                 }
@@ -192,36 +336,197 @@ impl Editor {
                     self.file = Some(path);
                     self.content = text_editor::Content::with_text(&contents);
                     self.is_dirty = false;
+                    self.has_disk_conflict = false;
+
+                    if let Some(line) = self.pending_cursor_line.take() {
+                        self.content.perform(text_editor::Action::Move(
+                            text_editor::Motion::DocumentStart,
+                        ));
+                        for _ in 0..line {
+                            self.content
+                                .perform(text_editor::Action::Move(text_editor::Motion::Down));
+                        }
+                    }
                 }
                 Task::none()
             }
             Message::WindowOpened(id) => {
                 Task::batch(vec![window::change_mode(id, Mode::Fullscreen)])
             }
+            Message::FileChangedOnDisk(changed_path) => {
+                if self.file.as_ref() != Some(&changed_path) {
+                    Task::none()
+                } else {
+                    // AutoSave's own write re-enters here via the watcher,
+                    // so compare the new content against our last write
+                    // before treating it as an external change.
+                    Task::perform(
+                        classify_disk_change(changed_path, self.last_self_write.clone()),
+                        Message::DiskChangeClassified,
+                    )
+                }
+            }
+            Message::DiskChangeClassified(DiskChange::SelfEcho) => Task::none(),
+            Message::DiskChangeClassified(DiskChange::Changed(path)) => {
+                if self.is_dirty {
+                    // Don't clobber unsaved edits - surface the conflict
+                    // instead and let the user pick reload or keep.
+                    self.has_disk_conflict = true;
+                    Task::none()
+                } else {
+                    Task::perform(reload_file(path), Message::FileLoaded)
+                }
+            }
+            Message::ReloadFromDisk => {
+                self.has_disk_conflict = false;
+                if let Some(path) = self.file.clone() {
+                    Task::perform(reload_file(path), Message::FileLoaded)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::IndexEntryChanged(path) => {
+                self.index.refresh(&path);
+                self.search_index.reindex(&path);
+                if let Some(switcher) = &mut self.switcher {
+                    switcher.results =
+                        build_switcher_results(&self.index, &switcher.query, &self.nav_config);
+                }
+                if let Some(content_search) = &mut self.content_search {
+                    content_search.results = self.search_index.search(&content_search.query);
+                }
+                Task::none()
+            }
+            Message::IndexEntryRemoved(path) => {
+                self.index.remove(&path);
+                self.search_index.remove(&path);
+                if let Some(switcher) = &mut self.switcher {
+                    switcher.results =
+                        build_switcher_results(&self.index, &switcher.query, &self.nav_config);
+                }
+                if let Some(content_search) = &mut self.content_search {
+                    content_search.results = self.search_index.search(&content_search.query);
+                }
+                Task::none()
+            }
+            Message::OpenSwitcher => {
+                self.switcher = Some(Switcher {
+                    query: String::new(),
+                    results: build_switcher_results(&self.index, "", &self.nav_config),
+                    selected: 0,
+                });
+                text_input::focus(switcher_query_id())
+            }
+            Message::CloseSwitcher => {
+                self.switcher = None;
+                Task::none()
+            }
+            Message::SwitcherQueryChanged(query) => {
+                if let Some(switcher) = &mut self.switcher {
+                    switcher.results =
+                        build_switcher_results(&self.index, &query, &self.nav_config);
+                    switcher.query = query;
+                    switcher.selected = 0;
+                }
+                Task::none()
+            }
+            Message::SwitcherSelectionMoved(delta) => {
+                if let Some(switcher) = &mut self.switcher {
+                    let len = switcher.results.len() as i32;
+                    if len > 0 {
+                        switcher.selected =
+                            (switcher.selected as i32 + delta).rem_euclid(len) as usize;
+                    }
+                }
+                Task::none()
+            }
+            Message::SwitcherFileChosen(path) => {
+                self.switcher = None;
+
+                let save_task = if self.is_dirty {
+                    let text = self.content.text();
+                    Task::perform(save_file(self.file.clone(), text), Message::FileSaved)
+                } else {
+                    Task::none()
+                };
+                let load_task = Task::perform(reload_file(path), Message::FileLoaded);
+
+                Task::batch([save_task, load_task])
+            }
+            Message::OpenContentSearch => {
+                self.content_search = Some(ContentSearch {
+                    query: String::new(),
+                    results: self.search_index.search(""),
+                    selected: 0,
+                });
+                text_input::focus(content_search_query_id())
+            }
+            Message::CloseContentSearch => {
+                self.content_search = None;
+                Task::none()
+            }
+            Message::SearchContent(query) => {
+                if let Some(content_search) = &mut self.content_search {
+                    content_search.results = self.search_index.search(&query);
+                    content_search.query = query;
+                    content_search.selected = 0;
+                }
+                Task::none()
+            }
+            Message::ContentSearchSelectionMoved(delta) => {
+                if let Some(content_search) = &mut self.content_search {
+                    let len = content_search.results.len() as i32;
+                    if len > 0 {
+                        content_search.selected =
+                            (content_search.selected as i32 + delta).rem_euclid(len) as usize;
+                    }
+                }
+                Task::none()
+            }
+            Message::ContentSearchResultChosen(path, line) => {
+                self.content_search = None;
+                self.pending_cursor_line = Some(line);
+
+                let save_task = if self.is_dirty {
+                    let text = self.content.text();
+                    Task::perform(save_file(self.file.clone(), text), Message::FileSaved)
+                } else {
+                    Task::none()
+                };
+                let load_task = Task::perform(reload_file(path), Message::FileLoaded);
+
+                Task::batch([save_task, load_task])
+            }
         }
     }
 
     fn view(&self) -> Element<Message> {
-        let status_text = if let Some(path) = &self.file {
+        let sort_mode = self.nav_config.describe();
+        let status_text = if self.has_disk_conflict {
+            "File changed on disk - Cmd+R: reload | keep typing to keep your version".to_string()
+        } else if let Some(path) = &self.file {
             format!(
-                "File: {} | {}:{} | Cmd+L: Prev | Cmd+P: Next | Cmd+N: New | Cmd+S: Save | ESC: Exit",
+                "File: {} | {}:{} | Sort: {} | Cmd+L: Prev | Cmd+P: Next | Cmd+O: Switch | Cmd+F: Search | Cmd+N: New | Cmd+S: Save | ESC: Exit",
                 path.display(),
                 self.content.cursor_position().0 + 1,
-                self.content.cursor_position().1 + 1
+                self.content.cursor_position().1 + 1,
+                sort_mode
             )
         } else {
             format!(
-                "New file | {}:{} | Cmd+L: Prev | Cmd+P: Next | Cmd+N: New | Cmd+S: Save | ESC: Exit",
+                "New file | {}:{} | Sort: {} | Cmd+L: Prev | Cmd+P: Next | Cmd+O: Switch | Cmd+F: Search | Cmd+N: New | Cmd+S: Save | ESC: Exit",
                 self.content.cursor_position().0 + 1,
-                self.content.cursor_position().1 + 1
+                self.content.cursor_position().1 + 1,
+                sort_mode
             )
         };
 
-        column![
+        let editor = column![
             text_editor(&self.content)
                 .height(Fill)
                 .on_action(Message::ActionPerformed)
                 .wrapping(text::Wrapping::Word)
+                .highlight(&self.highlighter_token(), highlighter::Theme::SolarizedDark)
                 .key_binding(|key_press| {
                     match key_press.key.as_ref() {
                         keyboard::Key::Character("s") if key_press.modifiers.command() => {
@@ -236,32 +541,248 @@ impl Editor {
                         keyboard::Key::Character("n") if key_press.modifiers.command() => {
                             Some(text_editor::Binding::Custom(Message::CreateNewFile))
                         }
+                        keyboard::Key::Character("r") if key_press.modifiers.command() => {
+                            Some(text_editor::Binding::Custom(Message::ReloadFromDisk))
+                        }
+                        keyboard::Key::Character("o") if key_press.modifiers.command() => {
+                            Some(text_editor::Binding::Custom(Message::OpenSwitcher))
+                        }
+                        keyboard::Key::Character("f") if key_press.modifiers.command() => {
+                            Some(text_editor::Binding::Custom(Message::OpenContentSearch))
+                        }
                         keyboard::Key::Named(keyboard::key::Named::Escape) => {
                             Some(text_editor::Binding::Custom(Message::WindowClosed))
                         }
-                        _ => text_editor::Binding::from_key_press(key_press),
+                        keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                            pairs::backspace_binding(&self.content, &self.pair_config)
+                                .or_else(|| text_editor::Binding::from_key_press(key_press))
+                        }
+                        _ => pairs::binding_for(
+                            &self.content,
+                            &self.pair_config,
+                            key_press.key.as_ref(),
+                            key_press.modifiers,
+                        )
+                        .or_else(|| text_editor::Binding::from_key_press(key_press)),
                     }
                 }),
             text(status_text),
         ]
         .spacing(10)
-        .padding(10)
-        .into()
+        .padding(10);
+
+        if let Some(content_search) = &self.content_search {
+            stack![editor, self.content_search_overlay(content_search)].into()
+        } else if let Some(switcher) = &self.switcher {
+            stack![editor, self.switcher_overlay(switcher)].into()
+        } else {
+            editor.into()
+        }
+    }
+
+    fn switcher_overlay<'a>(&self, switcher: &'a Switcher) -> Element<'a, Message> {
+        let query_input = text_input("Search notes by title...", &switcher.query)
+            .id(switcher_query_id())
+            .on_input(Message::SwitcherQueryChanged)
+            .on_submit(
+                switcher
+                    .results
+                    .get(switcher.selected)
+                    .map(|(entry, _)| Message::SwitcherFileChosen(entry.path.clone()))
+                    .unwrap_or(Message::CloseSwitcher),
+            )
+            .padding(8);
+
+        let results: Vec<Element<'a, Message>> = switcher
+            .results
+            .iter()
+            .enumerate()
+            .map(|(index, (entry, positions))| {
+                let label = highlighted_title(entry, positions);
+                button(label)
+                    .on_press(Message::SwitcherFileChosen(entry.path.clone()))
+                    .width(Length::Fill)
+                    .style(if index == switcher.selected {
+                        button::primary
+                    } else {
+                        button::text
+                    })
+                    .into()
+            })
+            .collect();
+
+        let panel = column![
+            query_input,
+            scrollable(column(results).spacing(4)).height(Length::Fixed(320.0))
+        ]
+        .spacing(8)
+        .padding(16)
+        .width(Length::Fixed(480.0));
+
+        container(panel)
+            .style(container::rounded_box)
+            .center(Length::Fill)
+            .into()
+    }
+
+    fn content_search_overlay<'a>(
+        &self,
+        content_search: &'a ContentSearch,
+    ) -> Element<'a, Message> {
+        let query_input = text_input("Search note contents...", &content_search.query)
+            .id(content_search_query_id())
+            .on_input(Message::SearchContent)
+            .on_submit(
+                content_search
+                    .results
+                    .get(content_search.selected)
+                    .map(|hit| Message::ContentSearchResultChosen(hit.path.clone(), hit.line))
+                    .unwrap_or(Message::CloseContentSearch),
+            )
+            .padding(8);
+
+        let results: Vec<Element<'a, Message>> = content_search
+            .results
+            .iter()
+            .enumerate()
+            .map(|(index, hit)| {
+                let label = text(format!(
+                    "{}:{}  —  {}",
+                    hit.path.display(),
+                    hit.line + 1,
+                    hit.snippet
+                ));
+                button(label)
+                    .on_press(Message::ContentSearchResultChosen(
+                        hit.path.clone(),
+                        hit.line,
+                    ))
+                    .width(Length::Fill)
+                    .style(if index == content_search.selected {
+                        button::primary
+                    } else {
+                        button::text
+                    })
+                    .into()
+            })
+            .collect();
+
+        let panel = column![
+            query_input,
+            scrollable(column(results).spacing(4)).height(Length::Fixed(320.0))
+        ]
+        .spacing(8)
+        .padding(16)
+        .width(Length::Fixed(560.0));
+
+        container(panel)
+            .style(container::rounded_box)
+            .center(Length::Fill)
+            .into()
     }
 
     fn theme(&self) -> Theme {
         Theme::Dark // Always dark theme for distraction-free
     }
 
+    /// The syntax token to highlight with, derived from the current file's
+    /// extension. Notes (`.txt`/`.md`, or no file yet) fall back to Markdown
+    /// so headings, emphasis, and code fences still stand out.
+    fn highlighter_token(&self) -> String {
+        match self
+            .file
+            .as_ref()
+            .and_then(|path| path.extension())
+            .and_then(|extension| extension.to_str())
+        {
+            Some(extension) if !extension.eq_ignore_ascii_case("txt") => extension.to_string(),
+            _ => "md".to_string(),
+        }
+    }
+
     fn subscription(&self) -> Subscription<Message> {
-        Subscription::batch([
+        let switcher_open = self.switcher.is_some();
+        let content_search_open = self.content_search.is_some();
+
+        let mut subscriptions = vec![
             time::every(Duration::from_secs(10)).map(|_| Message::AutoSave),
             window::close_events().map(|_| Message::WindowClosed),
             window::open_events().map(Message::WindowOpened),
-        ])
+            keyboard::on_key_press(move |key, _modifiers| {
+                if switcher_open {
+                    return match key.as_ref() {
+                        keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                            Some(Message::CloseSwitcher)
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                            Some(Message::SwitcherSelectionMoved(1))
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                            Some(Message::SwitcherSelectionMoved(-1))
+                        }
+                        _ => None,
+                    };
+                }
+
+                if content_search_open {
+                    return match key.as_ref() {
+                        keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                            Some(Message::CloseContentSearch)
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                            Some(Message::ContentSearchSelectionMoved(1))
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                            Some(Message::ContentSearchSelectionMoved(-1))
+                        }
+                        _ => None,
+                    };
+                }
+
+                None
+            }),
+        ];
+
+        if let Some(path) = &self.file {
+            subscriptions.push(watcher::watch(path.clone()));
+        }
+
+        Subscription::batch(subscriptions)
     }
 }
 
+/// Fuzzy-matches `query` against every indexed note's title, highest score
+/// first, keeping each match's character positions for highlighting.
+fn build_switcher_results(
+    index: &FileIndex,
+    query: &str,
+    nav_config: &NavigationConfig,
+) -> Vec<(FileEntry, Vec<usize>)> {
+    // Pre-sort by the configured navigation order so ties in fuzzy score
+    // (notably an empty query, where every entry scores 0) fall back to it.
+    let mut entries: Vec<PathBuf> = index
+        .entries()
+        .iter()
+        .map(|entry| entry.path.clone())
+        .collect();
+    nav_config.sort(&mut entries);
+
+    let mut results: Vec<(FileEntry, i64, Vec<usize>)> = entries
+        .iter()
+        .filter_map(|path| index.entries().iter().find(|entry| &entry.path == path))
+        .filter_map(|entry| {
+            index::fuzzy_match(query, &entry.title)
+                .map(|matched| (entry.clone(), matched.score, matched.positions))
+        })
+        .collect();
+
+    results.sort_by(|a, b| b.1.cmp(&a.1));
+    results
+        .into_iter()
+        .map(|(entry, _, positions)| (entry, positions))
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub enum Error {
     IoError(io::ErrorKind),
@@ -274,7 +795,7 @@ async fn create_new_file(path: PathBuf) -> Result<PathBuf, Error> {
     Ok(path)
 }
 
-async fn save_file(path: Option<PathBuf>, contents: String) -> Result<PathBuf, Error> {
+async fn save_file(path: Option<PathBuf>, contents: String) -> Result<(PathBuf, u64), Error> {
     let path = if let Some(path) = path {
         path
     } else {
@@ -283,15 +804,149 @@ async fn save_file(path: Option<PathBuf>, contents: String) -> Result<PathBuf, E
         PathBuf::from(filename)
     };
 
-    tokio::fs::write(&path, contents)
+    atomic_write(&path, contents.as_bytes()).await?;
+    Ok((path, hash_contents(&contents)))
+}
+
+/// Cheap content fingerprint used to tell our own autosave echoing back
+/// through the filesystem watcher apart from a genuine external edit.
+fn hash_contents(contents: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// How many prior versions of a file `atomic_write` keeps as rolling
+/// `.bak.N` backups (`.bak.1` most recent). AutoSave fires every 10s, so a
+/// single generation would erase the pre-edit version after two ticks -
+/// this gives a wider, genuinely "rolling" recovery window.
+const BACKUP_GENERATIONS: usize = 5;
+
+/// Writes `contents` to `path` without ever leaving a truncated file behind.
+///
+/// The bytes are written to a temporary sibling file, flushed and fsynced,
+/// then renamed over the destination - rename is atomic within a single
+/// filesystem, so a crash or power loss can only ever leave the old file or
+/// the new one in place, never a half-written one. If the previous version
+/// exists it is rolled into `.bak.1..BACKUP_GENERATIONS` first so a botched
+/// autosave is still recoverable even a few ticks later. The one exception
+/// is the cross-filesystem (`EXDEV`) fallback, which writes `path` directly
+/// and so is not atomic.
+async fn atomic_write(path: &std::path::Path, contents: &[u8]) -> Result<(), Error> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    tokio::fs::create_dir_all(&parent)
         .await
         .map_err(|error| Error::IoError(error.kind()))?;
-    Ok(path)
+
+    if tokio::fs::try_exists(path).await.unwrap_or(false) {
+        rotate_backups(path).await;
+        let backup_path = backup_path_for(path, 1);
+        // Best-effort: a failed backup shouldn't block the save itself.
+        let _ = tokio::fs::copy(path, &backup_path).await;
+    }
+
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("untitled");
+    let tmp_path = parent.join(format!(".{}.tmp-{}", file_name, std::process::id()));
+
+    let result = write_and_rename(&tmp_path, path, contents).await;
+
+    if let Err(error) = &result {
+        if error.raw_os_error() == Some(EXDEV) {
+            // Temp file and destination live on different filesystems, so
+            // rename can't be atomic - fall back to a direct write.
+            let fallback = tokio::fs::write(path, contents).await;
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return fallback.map_err(|error| Error::IoError(error.kind()));
+        }
+
+        let _ = tokio::fs::remove_file(&tmp_path).await;
+        return Err(Error::IoError(error.kind()));
+    }
+
+    Ok(())
+}
+
+async fn write_and_rename(
+    tmp_path: &std::path::Path,
+    path: &std::path::Path,
+    contents: &[u8],
+) -> io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut file = tokio::fs::File::create(tmp_path).await?;
+    file.write_all(contents).await?;
+    file.flush().await?;
+    file.sync_all().await?;
+    drop(file);
+
+    tokio::fs::rename(tmp_path, path).await
+}
+
+/// Shifts `path.bak.1..BACKUP_GENERATIONS-1` up to `path.bak.2..BACKUP_GENERATIONS`,
+/// dropping whatever occupied the oldest generation, so `.bak.1` is free for
+/// the file that's about to be overwritten.
+async fn rotate_backups(path: &std::path::Path) {
+    for generation in (1..BACKUP_GENERATIONS).rev() {
+        let from = backup_path_for(path, generation);
+        let to = backup_path_for(path, generation + 1);
+        // Best-effort, same as the backup copy itself: a missing older
+        // generation (not written yet) just means rename fails and we move on.
+        let _ = tokio::fs::rename(&from, &to).await;
+    }
+}
+
+fn backup_path_for(path: &std::path::Path, generation: usize) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("untitled");
+    path.with_file_name(format!("{}.bak.{}", file_name, generation))
+}
+
+/// `EXDEV`: "Invalid cross-device link", returned by `rename(2)` when the
+/// source and destination are on different filesystems.
+const EXDEV: i32 = 18;
+
+/// Re-reads `path` from disk, used to pick up external changes reported by
+/// the filesystem watcher.
+async fn reload_file(path: PathBuf) -> Result<(PathBuf, String), Error> {
+    let contents = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|error| Error::IoError(error.kind()))?;
+    Ok((path, contents))
+}
+
+/// Reads `path` and compares it against `last_self_write` to tell whether a
+/// `Message::FileChangedOnDisk` event is the watcher echoing our own
+/// autosave or a genuine external edit. Read failures are treated as real
+/// changes so a conflict still surfaces rather than being silently eaten.
+async fn classify_disk_change(
+    path: PathBuf,
+    last_self_write: Option<(PathBuf, u64)>,
+) -> DiskChange {
+    let Ok(contents) = tokio::fs::read_to_string(&path).await else {
+        return DiskChange::Changed(path);
+    };
+
+    match last_self_write {
+        Some((written_path, hash)) if written_path == path && hash_contents(&contents) == hash => {
+            DiskChange::SelfEcho
+        }
+        _ => DiskChange::Changed(path),
+    }
 }
 
 async fn find_and_load_file(
     current_file: Option<PathBuf>,
     find_previous: bool,
+    nav_config: NavigationConfig,
 ) -> Result<(PathBuf, String), Error> {
     use std::fs;
 
@@ -313,8 +968,8 @@ async fn find_and_load_file(
         })
         .collect();
 
-    // Sort files by name
-    txt_files.sort();
+    // Order files per the user's configured navigation order.
+    nav_config.sort(&mut txt_files);
 
     if txt_files.is_empty() {
         return Err(Error::IoError(io::ErrorKind::NotFound));
@@ -367,3 +1022,145 @@ async fn find_and_load_file(
 
     Ok((target_path, contents))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rs-iceq-main-test-{}-{name}", std::process::id()))
+    }
+
+    /// Removes a scratch file and every backup generation it may have
+    /// accumulated, so tests start and end on a clean slate.
+    async fn cleanup(path: &std::path::Path) {
+        let _ = tokio::fs::remove_file(path).await;
+        for generation in 1..=BACKUP_GENERATIONS + 1 {
+            let _ = tokio::fs::remove_file(backup_path_for(path, generation)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn atomic_write_creates_the_file_with_the_given_contents() {
+        let path = scratch_path("create");
+        cleanup(&path).await;
+
+        atomic_write(&path, b"hello").await.unwrap();
+        assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "hello");
+
+        cleanup(&path).await;
+    }
+
+    #[tokio::test]
+    async fn atomic_write_backs_up_the_previous_version_before_overwriting() {
+        let path = scratch_path("backup");
+        cleanup(&path).await;
+
+        atomic_write(&path, b"first").await.unwrap();
+        atomic_write(&path, b"second").await.unwrap();
+
+        assert_eq!(
+            tokio::fs::read_to_string(backup_path_for(&path, 1))
+                .await
+                .unwrap(),
+            "first"
+        );
+        assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "second");
+
+        cleanup(&path).await;
+    }
+
+    #[tokio::test]
+    async fn atomic_write_rolls_older_backups_into_higher_generations() {
+        let path = scratch_path("rotate");
+        cleanup(&path).await;
+
+        for contents in ["v1", "v2", "v3"] {
+            atomic_write(&path, contents.as_bytes()).await.unwrap();
+        }
+
+        // After three writes: current = v3, .bak.1 = v2 (the last
+        // overwrite), .bak.2 = v1 (rolled up from .bak.1).
+        assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "v3");
+        assert_eq!(
+            tokio::fs::read_to_string(backup_path_for(&path, 1))
+                .await
+                .unwrap(),
+            "v2"
+        );
+        assert_eq!(
+            tokio::fs::read_to_string(backup_path_for(&path, 2))
+                .await
+                .unwrap(),
+            "v1"
+        );
+
+        cleanup(&path).await;
+    }
+
+    #[tokio::test]
+    async fn atomic_write_never_keeps_more_than_backup_generations_versions() {
+        let path = scratch_path("cap");
+        cleanup(&path).await;
+
+        for index in 0..BACKUP_GENERATIONS + 3 {
+            atomic_write(&path, format!("v{index}").as_bytes())
+                .await
+                .unwrap();
+        }
+
+        let oldest_possible = backup_path_for(&path, BACKUP_GENERATIONS + 1);
+        assert!(!tokio::fs::try_exists(&oldest_possible).await.unwrap_or(false));
+
+        cleanup(&path).await;
+    }
+
+    // `atomic_write`'s EXDEV fallback (temp file and destination on
+    // different filesystems) isn't exercised here: reproducing it needs two
+    // distinct filesystems mounted in the test environment, which a plain
+    // `cargo test` sandbox doesn't provide.
+
+    #[tokio::test]
+    async fn classify_disk_change_recognizes_our_own_last_write_as_an_echo() {
+        let path = scratch_path("echo");
+        cleanup(&path).await;
+
+        let (written_path, hash) = save_file(Some(path.clone()), "hello".to_string())
+            .await
+            .unwrap();
+
+        let classified =
+            classify_disk_change(written_path.clone(), Some((written_path, hash))).await;
+        assert!(matches!(classified, DiskChange::SelfEcho));
+
+        cleanup(&path).await;
+    }
+
+    #[tokio::test]
+    async fn classify_disk_change_flags_a_genuine_external_edit() {
+        let path = scratch_path("external");
+        cleanup(&path).await;
+
+        let (written_path, hash) = save_file(Some(path.clone()), "hello".to_string())
+            .await
+            .unwrap();
+        tokio::fs::write(&written_path, "someone else edited this")
+            .await
+            .unwrap();
+
+        let classified =
+            classify_disk_change(written_path.clone(), Some((written_path, hash))).await;
+        assert!(matches!(classified, DiskChange::Changed(_)));
+
+        cleanup(&path).await;
+    }
+
+    #[tokio::test]
+    async fn classify_disk_change_treats_an_unreadable_path_as_changed() {
+        let path = scratch_path("missing");
+        cleanup(&path).await;
+
+        let classified = classify_disk_change(path.clone(), None).await;
+        assert!(matches!(classified, DiskChange::Changed(_)));
+    }
+}
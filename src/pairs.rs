@@ -0,0 +1,372 @@
+//! Auto-closing bracket and quote pairs for the editor's `key_binding`.
+//!
+//! This mirrors what closebrackets-style plugins do: typing an opening
+//! character inserts its matching closer and leaves the cursor between them;
+//! typing a closer that's already sitting to the right types over it instead
+//! of duplicating it; and Backspace deletes both halves of an empty pair.
+
+use iced::keyboard;
+use iced::widget::text_editor;
+
+pub type Pair = (char, char);
+
+/// Markdown-friendly pairs are included alongside the usual brackets and
+/// quotes, since this editor's primary use case is prose notes.
+pub const DEFAULT_PAIRS: &[Pair] = &[
+    ('(', ')'),
+    ('{', '}'),
+    ('[', ']'),
+    ('"', '"'),
+    ('\'', '\''),
+    ('`', '`'),
+    ('*', '*'),
+    ('_', '_'),
+];
+
+#[derive(Debug, Clone)]
+pub struct PairConfig {
+    pub pairs: Vec<Pair>,
+    /// When a selection is active, wrap it in the typed pair instead of
+    /// skipping auto-pairing entirely.
+    pub wrap_selection: bool,
+}
+
+impl Default for PairConfig {
+    fn default() -> Self {
+        Self {
+            pairs: DEFAULT_PAIRS.to_vec(),
+            wrap_selection: false,
+        }
+    }
+}
+
+impl PairConfig {
+    fn closer_for(&self, opener: char) -> Option<char> {
+        self.pairs
+            .iter()
+            .find(|(open, _)| *open == opener)
+            .map(|(_, close)| *close)
+    }
+
+    fn is_closer(&self, ch: char) -> bool {
+        self.pairs.iter().any(|(_, close)| *close == ch)
+    }
+}
+
+/// Produces an auto-pairing binding for `key_press`, if one applies.
+/// Returns `None` when the key should fall through to the default binding.
+pub fn binding_for<Message>(
+    content: &text_editor::Content,
+    config: &PairConfig,
+    key: keyboard::Key<&str>,
+    modifiers: keyboard::Modifiers,
+) -> Option<text_editor::Binding<Message>> {
+    if modifiers.command() || modifiers.control() || modifiers.alt() {
+        return None;
+    }
+
+    let keyboard::Key::Character(typed) = key else {
+        return None;
+    };
+    let mut chars = typed.chars();
+    let ch = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+
+    let has_selection = content
+        .selection()
+        .is_some_and(|selection| !selection.is_empty());
+
+    if has_selection {
+        return if config.wrap_selection {
+            let selected = content.selection()?;
+            let close = config.closer_for(ch)?;
+            let mut sequence = vec![text_editor::Binding::Insert(ch)];
+            sequence.extend(selected.chars().map(text_editor::Binding::Insert));
+            sequence.push(text_editor::Binding::Insert(close));
+            Some(text_editor::Binding::Sequence(sequence))
+        } else {
+            None
+        };
+    }
+
+    if config.is_closer(ch) && char_after_cursor(content) == Some(ch) {
+        return Some(text_editor::Binding::Move(text_editor::Motion::Right));
+    }
+
+    if let Some(close) = config.closer_for(ch) {
+        // Symmetric pairs (quotes, `*`, `_`) can't tell opener from closer
+        // by character alone, so unlike brackets they need a context check:
+        // only auto-pair right after whitespace, at line start, or right
+        // after another opener. Otherwise this is a contraction or
+        // mid-word punctuation like `don|t` -> `'`, and pairing it would
+        // leave a stray closer dangling in the text.
+        if close == ch && !at_symmetric_pair_context(content, config) {
+            return None;
+        }
+
+        return Some(text_editor::Binding::Sequence(vec![
+            text_editor::Binding::Insert(ch),
+            text_editor::Binding::Insert(close),
+            text_editor::Binding::Move(text_editor::Motion::Left),
+        ]));
+    }
+
+    None
+}
+
+/// Whether the cursor sits somewhere a symmetric pair's *opening* use is
+/// expected: start of line, after whitespace, or right after another
+/// opener (so nested/adjacent quoting still auto-pairs).
+fn at_symmetric_pair_context(content: &text_editor::Content, config: &PairConfig) -> bool {
+    match char_before_cursor(content) {
+        None => true,
+        Some(before) => {
+            before.is_whitespace() || config.pairs.iter().any(|(open, _)| *open == before)
+        }
+    }
+}
+
+/// Deletes both halves of an empty pair when Backspace is pressed between
+/// them (e.g. `(|)` becomes `|`); returns `None` otherwise.
+pub fn backspace_binding<Message>(
+    content: &text_editor::Content,
+    config: &PairConfig,
+) -> Option<text_editor::Binding<Message>> {
+    let before = char_before_cursor(content)?;
+    let after = char_after_cursor(content)?;
+
+    if config.closer_for(before) == Some(after) {
+        Some(text_editor::Binding::Sequence(vec![
+            text_editor::Binding::Backspace,
+            text_editor::Binding::Delete,
+        ]))
+    } else {
+        None
+    }
+}
+
+fn current_line(content: &text_editor::Content) -> Option<String> {
+    let (line_index, _) = content.cursor_position();
+    content.line(line_index).map(|line| line.to_string())
+}
+
+fn char_before_cursor(content: &text_editor::Content) -> Option<char> {
+    let (_, column) = content.cursor_position();
+    let line = current_line(content)?;
+    column
+        .checked_sub(1)
+        .and_then(|index| line.chars().nth(index))
+}
+
+fn char_after_cursor(content: &text_editor::Content) -> Option<char> {
+    let (_, column) = content.cursor_position();
+    let line = current_line(content)?;
+    line.chars().nth(column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_with_cursor_at(text: &str, column: usize) -> text_editor::Content {
+        let mut content = text_editor::Content::with_text(text);
+        for _ in 0..column {
+            content.perform(text_editor::Action::Move(text_editor::Motion::Right));
+        }
+        content
+    }
+
+    fn no_modifiers() -> keyboard::Modifiers {
+        keyboard::Modifiers::default()
+    }
+
+    #[test]
+    fn typing_an_opener_inserts_its_closer_and_parks_cursor_between_them() {
+        let content = content_with_cursor_at("", 0);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("("),
+            no_modifiers(),
+        );
+
+        assert!(matches!(binding, Some(text_editor::Binding::Sequence(_))));
+    }
+
+    #[test]
+    fn typing_an_unpaired_character_falls_through_to_the_default_binding() {
+        let content = content_with_cursor_at("", 0);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("x"),
+            no_modifiers(),
+        );
+
+        assert!(binding.is_none());
+    }
+
+    #[test]
+    fn typing_a_closer_already_sitting_at_the_cursor_types_over_it() {
+        let content = content_with_cursor_at("()", 1);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character(")"),
+            no_modifiers(),
+        );
+
+        assert!(matches!(
+            binding,
+            Some(text_editor::Binding::Move(text_editor::Motion::Right))
+        ));
+    }
+
+    #[test]
+    fn a_held_modifier_key_disables_auto_pairing() {
+        let content = content_with_cursor_at("", 0);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("("),
+            keyboard::Modifiers::CTRL,
+        );
+
+        assert!(binding.is_none());
+    }
+
+    #[test]
+    fn typing_over_a_selection_is_ignored_unless_wrap_selection_is_enabled() {
+        let mut content = text_editor::Content::with_text("hello");
+        content.perform(text_editor::Action::Select(text_editor::Motion::Right));
+
+        let mut config = PairConfig::default();
+        config.wrap_selection = false;
+        assert!(binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("("),
+            no_modifiers()
+        )
+        .is_none());
+
+        config.wrap_selection = true;
+        assert!(matches!(
+            binding_for::<()>(
+                &content,
+                &config,
+                keyboard::Key::Character("("),
+                no_modifiers()
+            ),
+            Some(text_editor::Binding::Sequence(_))
+        ));
+    }
+
+    #[test]
+    fn backspace_between_an_empty_pair_deletes_both_halves() {
+        let content = content_with_cursor_at("()", 1);
+        let config = PairConfig::default();
+
+        let binding = backspace_binding::<()>(&content, &config);
+
+        assert!(matches!(binding, Some(text_editor::Binding::Sequence(_))));
+    }
+
+    #[test]
+    fn backspace_with_non_empty_contents_between_the_pair_falls_through() {
+        let content = content_with_cursor_at("(x)", 2);
+        let config = PairConfig::default();
+
+        assert!(backspace_binding::<()>(&content, &config).is_none());
+    }
+
+    #[test]
+    fn typing_a_quote_mid_word_falls_through_instead_of_auto_pairing() {
+        // "it|'s" - cursor right after the 't' in "it", not at a word
+        // boundary, so this is a contraction's apostrophe, not an opener.
+        let content = content_with_cursor_at("it", 2);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("'"),
+            no_modifiers(),
+        );
+
+        assert!(binding.is_none());
+    }
+
+    #[test]
+    fn typing_a_quote_after_whitespace_still_auto_pairs() {
+        let content = content_with_cursor_at("say ", 4);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("\""),
+            no_modifiers(),
+        );
+
+        assert!(matches!(binding, Some(text_editor::Binding::Sequence(_))));
+    }
+
+    #[test]
+    fn typing_a_quote_at_start_of_line_still_auto_pairs() {
+        let content = content_with_cursor_at("", 0);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("'"),
+            no_modifiers(),
+        );
+
+        assert!(matches!(binding, Some(text_editor::Binding::Sequence(_))));
+    }
+
+    #[test]
+    fn typing_a_quote_right_after_an_opener_still_auto_pairs() {
+        // `('|)` - nested quoting right after `(` should still pair.
+        let content = content_with_cursor_at("()", 1);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("'"),
+            no_modifiers(),
+        );
+
+        assert!(matches!(binding, Some(text_editor::Binding::Sequence(_))));
+    }
+
+    #[test]
+    fn bracket_pairs_are_unaffected_by_the_symmetric_pair_context_check() {
+        // `(` isn't symmetric (its closer is `)`), so it should still
+        // auto-pair mid-word same as before this change.
+        let content = content_with_cursor_at("foo", 3);
+        let config = PairConfig::default();
+
+        let binding = binding_for::<()>(
+            &content,
+            &config,
+            keyboard::Key::Character("("),
+            no_modifiers(),
+        );
+
+        assert!(matches!(binding, Some(text_editor::Binding::Sequence(_))));
+    }
+}
@@ -0,0 +1,264 @@
+//! A full-text inverted index over the `.txt` notes in the working
+//! directory: lowercased token -> file -> line numbers the token appears on.
+//! Built once at startup and kept fresh incrementally from filesystem-watcher
+//! events, so a note's postings are rebuilt only when that note changes.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// The shortest token worth indexing; anything shorter is noise (and there's
+/// a lot of it - "a", "i", single digits, ...) that would bloat the index for no
+/// search value.
+const MIN_TOKEN_LEN: usize = 2;
+
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    /// token -> file -> sorted, deduplicated line numbers containing it.
+    postings: HashMap<String, HashMap<PathBuf, Vec<usize>>>,
+    /// The set of tokens each file contributed, so a re-index can remove
+    /// exactly its old postings without touching any other file's.
+    file_tokens: HashMap<PathBuf, HashSet<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub path: PathBuf,
+    pub line: usize,
+    pub snippet: String,
+}
+
+impl SearchIndex {
+    /// Builds the index from every `.txt` file directly under `dir`.
+    pub fn scan(dir: &Path) -> Self {
+        let mut index = Self::default();
+        let Ok(read_dir) = std::fs::read_dir(dir) else {
+            return index;
+        };
+
+        for entry in read_dir.filter_map(Result::ok) {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("txt") {
+                index.reindex(&path);
+            }
+        }
+
+        index
+    }
+
+    /// Rebuilds a single file's postings, used when the filesystem watcher
+    /// reports it was created or modified.
+    pub fn reindex(&mut self, path: &Path) {
+        self.remove(path);
+
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return;
+        };
+
+        let mut tokens = HashSet::new();
+        for (line_number, line) in contents.lines().enumerate() {
+            // Dedupe within the line first - a token repeated on one line
+            // (e.g. "apple apple") must still only contribute one entry to
+            // that line's postings, matching the "deduplicated" invariant.
+            let line_tokens: HashSet<String> = tokenize(line).collect();
+            for token in line_tokens {
+                self.postings
+                    .entry(token.clone())
+                    .or_default()
+                    .entry(path.to_path_buf())
+                    .or_default()
+                    .push(line_number);
+                tokens.insert(token);
+            }
+        }
+
+        self.file_tokens.insert(path.to_path_buf(), tokens);
+    }
+
+    /// Drops every posting contributed by `path`, used on delete and before
+    /// a reindex.
+    pub fn remove(&mut self, path: &Path) {
+        let Some(tokens) = self.file_tokens.remove(path) else {
+            return;
+        };
+
+        for token in tokens {
+            if let Some(files) = self.postings.get_mut(&token) {
+                files.remove(path);
+                if files.is_empty() {
+                    self.postings.remove(&token);
+                }
+            }
+        }
+    }
+
+    /// Finds every file containing *all* of `query`'s terms (AND-combined),
+    /// ranked by summed term frequency (the number of lines each term appears
+    /// on), with a one-line snippet from the first line holding every term,
+    /// or the earliest line holding any term when no line holds them all.
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        let terms: Vec<String> = tokenize(query).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut candidates: Option<HashSet<PathBuf>> = None;
+        for term in &terms {
+            let files: HashSet<PathBuf> = self
+                .postings
+                .get(term)
+                .map(|files| files.keys().cloned().collect())
+                .unwrap_or_default();
+
+            candidates = Some(match candidates {
+                Some(existing) => existing.intersection(&files).cloned().collect(),
+                None => files,
+            });
+
+            if candidates.as_ref().is_some_and(HashSet::is_empty) {
+                break;
+            }
+        }
+
+        let Some(candidates) = candidates else {
+            return Vec::new();
+        };
+
+        let mut scored: Vec<(PathBuf, u32, usize)> = candidates
+            .into_iter()
+            .map(|path| {
+                let mut term_frequency = 0u32;
+                let mut common_lines: Option<HashSet<usize>> = None;
+                let mut any_line = usize::MAX;
+
+                for term in &terms {
+                    if let Some(lines) = self.postings.get(term).and_then(|files| files.get(&path))
+                    {
+                        term_frequency += lines.len() as u32;
+                        if let Some(&first) = lines.first() {
+                            any_line = any_line.min(first);
+                        }
+
+                        let line_set: HashSet<usize> = lines.iter().copied().collect();
+                        common_lines = Some(match common_lines {
+                            Some(existing) => existing.intersection(&line_set).copied().collect(),
+                            None => line_set,
+                        });
+                    }
+                }
+
+                // Prefer a line every term actually appears on; the
+                // AND-intersection above is per file, so the terms may sit on
+                // different lines - then fall back to the earliest line any
+                // term appears on.
+                let best_line = common_lines
+                    .filter(|lines| !lines.is_empty())
+                    .and_then(|lines| lines.into_iter().min())
+                    .unwrap_or(any_line);
+
+                (path, term_frequency, best_line)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.cmp(&a.1));
+
+        scored
+            .into_iter()
+            .map(|(path, _, line)| {
+                let snippet = read_line(&path, line).unwrap_or_default();
+                SearchHit {
+                    path,
+                    line,
+                    snippet,
+                }
+            })
+            .collect()
+    }
+}
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.to_lowercase())
+        .filter(|word| word.len() >= MIN_TOKEN_LEN)
+}
+
+fn read_line(path: &Path, line: usize) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()?
+        .lines()
+        .nth(line)
+        .map(|line| line.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_splits_on_non_alphanumeric_and_drops_short_tokens() {
+        let tokens: Vec<String> = tokenize("Hello, world! a to iced-editor").collect();
+        assert_eq!(tokens, vec!["hello", "world", "to", "iced", "editor"]);
+    }
+
+    /// A scratch `.txt` file under the system temp dir, removed on drop, so
+    /// each test gets its own isolated file without a shared fixtures dir.
+    struct ScratchFile {
+        path: PathBuf,
+    }
+
+    impl ScratchFile {
+        fn new(name: &str, contents: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "rs-iceq-search-test-{}-{}-{}.txt",
+                std::process::id(),
+                name,
+                contents.len()
+            ));
+            std::fs::write(&path, contents).unwrap();
+            Self { path }
+        }
+    }
+
+    impl Drop for ScratchFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+
+    #[test]
+    fn reindex_dedupes_a_token_repeated_on_one_line() {
+        let file = ScratchFile::new("dedupe", "apple apple apple\n");
+        let mut index = SearchIndex::default();
+        index.reindex(&file.path);
+
+        let lines = index
+            .postings
+            .get("apple")
+            .and_then(|files| files.get(&file.path))
+            .unwrap();
+        assert_eq!(lines, &vec![0]);
+    }
+
+    #[test]
+    fn search_excludes_files_missing_any_term() {
+        let file = ScratchFile::new("and-miss", "apple pie\njust crumble\n");
+        let mut index = SearchIndex::default();
+        index.reindex(&file.path);
+
+        assert_eq!(index.search("apple banana").len(), 0);
+        assert_eq!(index.search("apple pie").len(), 1);
+    }
+
+    #[test]
+    fn search_picks_a_line_where_every_term_actually_co_occurs() {
+        // "apple" first appears on line 0 and "banana" on line 1, but only
+        // line 2 contains both terms - the reported hit must point there,
+        // not at the earliest line of either term.
+        let file = ScratchFile::new("cooccur", "apple\nbanana\napple banana\n");
+        let mut index = SearchIndex::default();
+        index.reindex(&file.path);
+
+        let hits = index.search("apple banana");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].line, 2);
+    }
+}
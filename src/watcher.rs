@@ -0,0 +1,163 @@
+//! Watches a note file (and its parent directory) for external changes so
+//! the editor can react when another program edits or replaces it on disk.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use iced::futures::SinkExt;
+use iced::Subscription;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::Message;
+
+/// Debounce window: filesystem events for the same file tend to arrive in a
+/// burst (editors often write-then-rename), so we coalesce anything within
+/// this window into one signal per affected path.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Watches `active_file`'s parent directory for `.txt` changes.
+///
+/// Emits `Message::FileChangedOnDisk` when `active_file` itself is touched,
+/// and `Message::IndexEntryChanged` / `Message::IndexEntryRemoved` for every
+/// `.txt` file that changed, which keeps the note index and the full-text
+/// search index (and therefore the quick switcher and content search) fresh
+/// without a full rescan.
+pub fn watch(active_file: PathBuf) -> Subscription<Message> {
+    Subscription::run_with_id(
+        ("file-watcher", active_file.clone()),
+        iced::stream::channel(100, move |mut output| {
+            let active_file = active_file.clone();
+            async move {
+                let (tx, mut rx) = tokio::sync::mpsc::channel(100);
+
+                let mut watcher = match notify::recommended_watcher(
+                    move |event: notify::Result<notify::Event>| {
+                        if let Ok(event) = event {
+                            let _ = tx.blocking_send(event);
+                        }
+                    },
+                ) {
+                    Ok(watcher) => watcher,
+                    Err(_) => return,
+                };
+
+                let watch_dir: &Path = active_file
+                    .parent()
+                    .filter(|parent| !parent.as_os_str().is_empty())
+                    .unwrap_or_else(|| Path::new("."));
+
+                if watcher
+                    .watch(watch_dir, RecursiveMode::NonRecursive)
+                    .is_err()
+                {
+                    return;
+                }
+
+                loop {
+                    let Some(first) = rx.recv().await else {
+                        break;
+                    };
+                    let mut touched = txt_paths(&first);
+
+                    // Drain anything else that arrives within the debounce
+                    // window so a burst of events collapses into one signal.
+                    loop {
+                        match tokio::time::timeout(DEBOUNCE, rx.recv()).await {
+                            Ok(Some(event)) => touched.extend(txt_paths(&event)),
+                            Ok(None) => break,
+                            Err(_) => break,
+                        }
+                    }
+
+                    for path in touched {
+                        let message = if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+                            Message::IndexEntryChanged(path.clone())
+                        } else {
+                            Message::IndexEntryRemoved(path.clone())
+                        };
+                        if output.send(message).await.is_err() {
+                            return;
+                        }
+
+                        if path == active_file
+                            && output.send(Message::FileChangedOnDisk(path)).await.is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+        }),
+    )
+}
+
+/// Extracts the `.txt` paths this event touched (create, modify, or remove).
+fn txt_paths(event: &notify::Event) -> HashSet<PathBuf> {
+    if !matches!(
+        event.kind,
+        notify::EventKind::Create(_) | notify::EventKind::Modify(_) | notify::EventKind::Remove(_)
+    ) {
+        return HashSet::new();
+    }
+
+    event
+        .paths
+        .iter()
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("txt"))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, ModifyKind, RemoveKind};
+    use notify::{Event, EventKind};
+
+    fn event_for(kind: EventKind, paths: &[&str]) -> Event {
+        paths
+            .iter()
+            .fold(Event::new(kind), |event, path| event.add_path(path.into()))
+    }
+
+    fn txt_set(paths: &[&str]) -> HashSet<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn txt_paths_includes_a_created_txt_file() {
+        let event = event_for(EventKind::Create(CreateKind::File), &["note.txt"]);
+        assert_eq!(txt_paths(&event), txt_set(&["note.txt"]));
+    }
+
+    #[test]
+    fn txt_paths_includes_a_modified_txt_file() {
+        let event = event_for(EventKind::Modify(ModifyKind::Any), &["note.txt"]);
+        assert_eq!(txt_paths(&event), txt_set(&["note.txt"]));
+    }
+
+    #[test]
+    fn txt_paths_includes_a_removed_txt_file() {
+        let event = event_for(EventKind::Remove(RemoveKind::File), &["note.txt"]);
+        assert_eq!(txt_paths(&event), txt_set(&["note.txt"]));
+    }
+
+    #[test]
+    fn txt_paths_excludes_non_txt_files() {
+        let event = event_for(EventKind::Create(CreateKind::File), &["image.png"]);
+        assert!(txt_paths(&event).is_empty());
+    }
+
+    #[test]
+    fn txt_paths_filters_out_non_txt_paths_within_a_mixed_event() {
+        let event = event_for(EventKind::Modify(ModifyKind::Any), &["note.txt", "image.png"]);
+        assert_eq!(txt_paths(&event), txt_set(&["note.txt"]));
+    }
+
+    #[test]
+    fn txt_paths_ignores_event_kinds_other_than_create_modify_remove() {
+        let event = event_for(EventKind::Access(AccessKind::Any), &["note.txt"]);
+        assert!(txt_paths(&event).is_empty());
+    }
+}